@@ -98,6 +98,41 @@ pub use scalar::bvec3a::{bvec3a, BVec3A};
 ))]
 pub use scalar::bvec4a::{bvec4a, BVec4A};
 
+/// Packs the bitmasks of `masks` into bytes, two masks per byte.
+///
+/// The first mask of each pair is stored in the low 4 bits of the byte. If `masks` has an odd
+/// length the high 4 bits of the last byte are zero.
+///
+/// This function is only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn pack_masks(masks: &[BVec4A]) -> alloc::vec::Vec<u8> {
+    masks
+        .chunks(2)
+        .map(|pair| {
+            pair.iter()
+                .rev()
+                .fold(0, |bits, mask| (bits << 4) | mask.bitmask() as u8)
+        })
+        .collect()
+}
+
+/// Unpacks `count` masks from bytes packed by [`pack_masks`].
+///
+/// Panics if `bytes` contains fewer than `count` masks.
+///
+/// This function is only available with the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn unpack_masks(bytes: &[u8], count: usize) -> alloc::vec::Vec<BVec4A> {
+    (0..count)
+        .map(|i| {
+            let bits = bytes[i / 2] >> ((i % 2) * 4);
+            BVec4A::from_fn(|lane| bits & (1 << lane) != 0)
+        })
+        .collect()
+}
+
 mod const_test_bvec2 {
     const_assert_eq!(1, core::mem::align_of::<super::BVec2>());
     const_assert_eq!(2, core::mem::size_of::<super::BVec2>());
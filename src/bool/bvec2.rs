@@ -11,7 +11,7 @@ pub const fn bvec2(x: bool, y: bool) -> BVec2 {
 }
 
 /// A 2-dimensional `bool` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(1))]
 #[cfg_attr(target_arch = "spirv", rust_gpu::vector::v1)]
 pub struct BVec2 {
@@ -49,6 +49,16 @@ impl BVec2 {
         Self::new(a[0], a[1])
     }
 
+    /// Creates a new vector mask by calling `f` with the index of each element.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::new(f(0), f(1))
+    }
+
     /// Returns a bitmask with the lowest 2 bits set from the elements of `self`.
     ///
     /// A true element results in a `1` bit and a false element in a `0` bit.  Element `x` goes
@@ -73,6 +83,47 @@ impl BVec2 {
         self.x && self.y
     }
 
+    /// Returns true if the first `n` elements are all true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn all_n(self, n: usize) -> bool {
+        assert!(n <= 2, "n out of bounds");
+        let mask = (1 << n) - 1;
+        self.bitmask() & mask == mask
+    }
+
+    /// Returns true if any of the first `n` elements are true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn any_n(self, n: usize) -> bool {
+        assert!(n <= 2, "n out of bounds");
+        self.bitmask() & ((1 << n) - 1) != 0
+    }
+
+    /// Returns true if `f` returns true for the index of every element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn all_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).all()
+    }
+
+    /// Returns true if `f` returns true for the index of any element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn any_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).any()
+    }
+
     /// Tests the value at `index`.
     ///
     /// Panics if `index` is greater than 1.
@@ -86,6 +137,17 @@ impl BVec2 {
         }
     }
 
+    /// Returns the value at `index`, or `None` if `index` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn get(self, index: usize) -> Option<bool> {
+        if index < 2 {
+            Some(self.test(index))
+        } else {
+            None
+        }
+    }
+
     /// Sets the element at `index`.
     ///
     /// Panics if `index` is greater than 1.
@@ -98,6 +160,168 @@ impl BVec2 {
         }
     }
 
+    /// Returns a vector mask containing the logical implication `self -> rhs` of each element.
+    ///
+    /// An element is false only when it is true in `self` and false in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn implies(self, rhs: Self) -> Self {
+        !self | rhs
+    }
+
+    /// Returns a vector mask containing the logical equivalence `self <-> rhs` of each element.
+    ///
+    /// An element is true when it is the same in `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn iff(self, rhs: Self) -> Self {
+        !(self ^ rhs)
+    }
+
+    /// Returns the index of the first true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn first_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some(bitmask.trailing_zeros() as usize)
+        }
+    }
+
+    /// Returns the index of the last true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn last_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some((31 - bitmask.leading_zeros()) as usize)
+        }
+    }
+
+    /// Returns the number of consecutive true elements starting from `x`.
+    #[inline]
+    #[must_use]
+    pub fn leading_trues(self) -> u32 {
+        self.bitmask().trailing_ones()
+    }
+
+    /// Returns the number of consecutive true elements starting from `y` going backwards.
+    #[inline]
+    #[must_use]
+    pub fn trailing_trues(self) -> u32 {
+        (self.bitmask() << 30).leading_ones()
+    }
+
+    /// Toggles the element at `index`.
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    pub fn toggle(&mut self, index: usize) {
+        assert!(index < 2, "index out of bounds");
+        *self ^= Self::from_fn(|i| i == index);
+    }
+
+    /// Returns a copy of `self` with the element at `index` toggled.
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn toggled(mut self, index: usize) -> Self {
+        self.toggle(index);
+        self
+    }
+
+    /// Returns a vector mask with all elements set to the element of `self` at `index`.
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    #[must_use]
+    pub fn splat_lane(self, index: usize) -> Self {
+        Self::splat(self.test(index))
+    }
+
+    /// Returns the element of `values` at the index of the first true element of `self`, or
+    /// `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn select_first_true_lane<T>(self, values: [T; 2]) -> Option<T> {
+        let index = self.first_set()?;
+        values.into_iter().nth(index)
+    }
+
+    /// Folds every element of `self` into an accumulator by applying `f` to each element in
+    /// order, starting with `x`.
+    #[inline]
+    #[must_use]
+    pub fn fold<T, F>(self, init: T, f: F) -> T
+    where
+        F: FnMut(T, bool) -> T,
+    {
+        self.into_bool_array().into_iter().fold(init, f)
+    }
+
+    /// Creates an array from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    #[must_use]
+    pub fn select_array<T: Copy>(self, if_true: [T; 2], if_false: [T; 2]) -> [T; 2] {
+        let mask = self.into_bool_array();
+        core::array::from_fn(|i| if mask[i] { if_true[i] } else { if_false[i] })
+    }
+
+    /// Returns a vector mask with the elements of `self` in reverse order.
+    #[inline]
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let [x, y] = self.into_bool_array();
+        Self::new(y, x)
+    }
+
+    /// Returns the number of adjacent pairs of elements in `self` which differ.
+    #[inline]
+    #[must_use]
+    pub fn count_transitions(self) -> u32 {
+        let bitmask = self.bitmask();
+        ((bitmask ^ (bitmask >> 1)) & 0b1).count_ones()
+    }
+
+    /// Returns a vector mask with every element of `self` inverted.
+    ///
+    /// This is computed by XOR with [`Self::TRUE`] and is equivalent to `!self`.
+    #[inline]
+    #[must_use]
+    pub fn xor_all(self) -> Self {
+        self ^ Self::TRUE
+    }
+
+    /// Returns the index of the only true element, or `None` if no elements or more than one
+    /// element are true.
+    #[inline]
+    #[must_use]
+    pub fn single(self) -> Option<usize> {
+        if self.bitmask().count_ones() == 1 {
+            self.first_set()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a vector mask containing the result of `self & !rhs` for each element.
+    ///
+    /// This can be used to clear the elements of `self` which are true in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn and_not(self, rhs: Self) -> Self {
+        self & !rhs
+    }
+
     #[inline]
     #[must_use]
     fn into_bool_array(self) -> [bool; 2] {
@@ -118,6 +342,13 @@ impl Default for BVec2 {
     }
 }
 
+impl core::hash::Hash for BVec2 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec2 {
     type Output = Self;
     #[inline]
@@ -269,10 +500,7 @@ impl Not for BVec2 {
     type Output = Self;
     #[inline]
     fn not(self) -> Self {
-        Self {
-            x: !self.x,
-            y: !self.y,
-        }
+        self.xor_all()
     }
 }
 
@@ -284,6 +512,18 @@ impl Not for &BVec2 {
     }
 }
 
+impl Index<usize> for BVec2 {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
 impl fmt::Debug for BVec2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let arr = self.into_u32_array();
@@ -312,9 +552,31 @@ impl From<BVec2> for [bool; 2] {
     }
 }
 
+impl From<[u32; 2]> for BVec2 {
+    /// Creates a vector mask treating any non-zero element of `a` as true.
+    #[inline]
+    fn from(a: [u32; 2]) -> Self {
+        Self::new(a[0] != 0, a[1] != 0)
+    }
+}
+
 impl From<BVec2> for [u32; 2] {
     #[inline]
     fn from(mask: BVec2) -> Self {
         mask.into_u32_array()
     }
 }
+
+impl PartialEq<[bool; 2]> for BVec2 {
+    #[inline]
+    fn eq(&self, rhs: &[bool; 2]) -> bool {
+        self.into_bool_array().eq(rhs)
+    }
+}
+
+impl PartialEq<BVec2> for [bool; 2] {
+    #[inline]
+    fn eq(&self, rhs: &BVec2) -> bool {
+        self.eq(&rhs.into_bool_array())
+    }
+}
@@ -11,7 +11,7 @@ pub const fn bvec3(x: bool, y: bool, z: bool) -> BVec3 {
 }
 
 /// A 3-dimensional `bool` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(1))]
 #[cfg_attr(target_arch = "spirv", rust_gpu::vector::v1)]
 pub struct BVec3 {
@@ -50,6 +50,16 @@ impl BVec3 {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector mask by calling `f` with the index of each element.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
     /// Returns a bitmask with the lowest 3 bits set from the elements of `self`.
     ///
     /// A true element results in a `1` bit and a false element in a `0` bit.  Element `x` goes
@@ -74,6 +84,47 @@ impl BVec3 {
         self.x && self.y && self.z
     }
 
+    /// Returns true if the first `n` elements are all true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn all_n(self, n: usize) -> bool {
+        assert!(n <= 3, "n out of bounds");
+        let mask = (1 << n) - 1;
+        self.bitmask() & mask == mask
+    }
+
+    /// Returns true if any of the first `n` elements are true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn any_n(self, n: usize) -> bool {
+        assert!(n <= 3, "n out of bounds");
+        self.bitmask() & ((1 << n) - 1) != 0
+    }
+
+    /// Returns true if `f` returns true for the index of every element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn all_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).all()
+    }
+
+    /// Returns true if `f` returns true for the index of any element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn any_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).any()
+    }
+
     /// Tests the value at `index`.
     ///
     /// Panics if `index` is greater than 2.
@@ -88,6 +139,17 @@ impl BVec3 {
         }
     }
 
+    /// Returns the value at `index`, or `None` if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn get(self, index: usize) -> Option<bool> {
+        if index < 3 {
+            Some(self.test(index))
+        } else {
+            None
+        }
+    }
+
     /// Sets the element at `index`.
     ///
     /// Panics if `index` is greater than 2.
@@ -101,6 +163,168 @@ impl BVec3 {
         }
     }
 
+    /// Returns a vector mask containing the logical implication `self -> rhs` of each element.
+    ///
+    /// An element is false only when it is true in `self` and false in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn implies(self, rhs: Self) -> Self {
+        !self | rhs
+    }
+
+    /// Returns a vector mask containing the logical equivalence `self <-> rhs` of each element.
+    ///
+    /// An element is true when it is the same in `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn iff(self, rhs: Self) -> Self {
+        !(self ^ rhs)
+    }
+
+    /// Returns the index of the first true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn first_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some(bitmask.trailing_zeros() as usize)
+        }
+    }
+
+    /// Returns the index of the last true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn last_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some((31 - bitmask.leading_zeros()) as usize)
+        }
+    }
+
+    /// Returns the number of consecutive true elements starting from `x`.
+    #[inline]
+    #[must_use]
+    pub fn leading_trues(self) -> u32 {
+        self.bitmask().trailing_ones()
+    }
+
+    /// Returns the number of consecutive true elements starting from `z` going backwards.
+    #[inline]
+    #[must_use]
+    pub fn trailing_trues(self) -> u32 {
+        (self.bitmask() << 29).leading_ones()
+    }
+
+    /// Toggles the element at `index`.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn toggle(&mut self, index: usize) {
+        assert!(index < 3, "index out of bounds");
+        *self ^= Self::from_fn(|i| i == index);
+    }
+
+    /// Returns a copy of `self` with the element at `index` toggled.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn toggled(mut self, index: usize) -> Self {
+        self.toggle(index);
+        self
+    }
+
+    /// Returns a vector mask with all elements set to the element of `self` at `index`.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn splat_lane(self, index: usize) -> Self {
+        Self::splat(self.test(index))
+    }
+
+    /// Returns the element of `values` at the index of the first true element of `self`, or
+    /// `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn select_first_true_lane<T>(self, values: [T; 3]) -> Option<T> {
+        let index = self.first_set()?;
+        values.into_iter().nth(index)
+    }
+
+    /// Folds every element of `self` into an accumulator by applying `f` to each element in
+    /// order, starting with `x`.
+    #[inline]
+    #[must_use]
+    pub fn fold<T, F>(self, init: T, f: F) -> T
+    where
+        F: FnMut(T, bool) -> T,
+    {
+        self.into_bool_array().into_iter().fold(init, f)
+    }
+
+    /// Creates an array from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    #[must_use]
+    pub fn select_array<T: Copy>(self, if_true: [T; 3], if_false: [T; 3]) -> [T; 3] {
+        let mask = self.into_bool_array();
+        core::array::from_fn(|i| if mask[i] { if_true[i] } else { if_false[i] })
+    }
+
+    /// Returns a vector mask with the elements of `self` in reverse order.
+    #[inline]
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let [x, y, z] = self.into_bool_array();
+        Self::new(z, y, x)
+    }
+
+    /// Returns the number of adjacent pairs of elements in `self` which differ.
+    #[inline]
+    #[must_use]
+    pub fn count_transitions(self) -> u32 {
+        let bitmask = self.bitmask();
+        ((bitmask ^ (bitmask >> 1)) & 0b11).count_ones()
+    }
+
+    /// Returns a vector mask with every element of `self` inverted.
+    ///
+    /// This is computed by XOR with [`Self::TRUE`] and is equivalent to `!self`.
+    #[inline]
+    #[must_use]
+    pub fn xor_all(self) -> Self {
+        self ^ Self::TRUE
+    }
+
+    /// Returns the index of the only true element, or `None` if no elements or more than one
+    /// element are true.
+    #[inline]
+    #[must_use]
+    pub fn single(self) -> Option<usize> {
+        if self.bitmask().count_ones() == 1 {
+            self.first_set()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a vector mask containing the result of `self & !rhs` for each element.
+    ///
+    /// This can be used to clear the elements of `self` which are true in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn and_not(self, rhs: Self) -> Self {
+        self & !rhs
+    }
+
     #[inline]
     #[must_use]
     fn into_bool_array(self) -> [bool; 3] {
@@ -125,6 +349,13 @@ impl Default for BVec3 {
     }
 }
 
+impl core::hash::Hash for BVec3 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec3 {
     type Output = Self;
     #[inline]
@@ -279,11 +510,7 @@ impl Not for BVec3 {
     type Output = Self;
     #[inline]
     fn not(self) -> Self {
-        Self {
-            x: !self.x,
-            y: !self.y,
-            z: !self.z,
-        }
+        self.xor_all()
     }
 }
 
@@ -295,6 +522,19 @@ impl Not for &BVec3 {
     }
 }
 
+impl Index<usize> for BVec3 {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
 impl fmt::Debug for BVec3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let arr = self.into_u32_array();
@@ -330,9 +570,31 @@ impl From<BVec3> for [bool; 3] {
     }
 }
 
+impl From<[u32; 3]> for BVec3 {
+    /// Creates a vector mask treating any non-zero element of `a` as true.
+    #[inline]
+    fn from(a: [u32; 3]) -> Self {
+        Self::new(a[0] != 0, a[1] != 0, a[2] != 0)
+    }
+}
+
 impl From<BVec3> for [u32; 3] {
     #[inline]
     fn from(mask: BVec3) -> Self {
         mask.into_u32_array()
     }
 }
+
+impl PartialEq<[bool; 3]> for BVec3 {
+    #[inline]
+    fn eq(&self, rhs: &[bool; 3]) -> bool {
+        self.into_bool_array().eq(rhs)
+    }
+}
+
+impl PartialEq<BVec3> for [bool; 3] {
+    #[inline]
+    fn eq(&self, rhs: &BVec3) -> bool {
+        self.eq(&rhs.into_bool_array())
+    }
+}
@@ -1,5 +1,7 @@
 // Generated from vec_mask.rs.tera template. Edit the template, not the generated file.
 
+use crate::Vec3A;
+
 use core::fmt;
 use core::ops::*;
 
@@ -60,6 +62,87 @@ impl BVec3A {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector mask by calling `f` with the index of each element.
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
+    /// Creates a vector mask containing the result of a `==` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmpeq`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_eq(a: Vec3A, b: Vec3A) -> Self {
+        a.cmpeq(b)
+    }
+
+    /// Creates a vector mask containing the result of a `!=` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmpne`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_ne(a: Vec3A, b: Vec3A) -> Self {
+        a.cmpne(b)
+    }
+
+    /// Creates a vector mask containing the result of a `>=` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmpge`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_ge(a: Vec3A, b: Vec3A) -> Self {
+        a.cmpge(b)
+    }
+
+    /// Creates a vector mask containing the result of a `>` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmpgt`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_gt(a: Vec3A, b: Vec3A) -> Self {
+        a.cmpgt(b)
+    }
+
+    /// Creates a vector mask containing the result of a `<=` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmple`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_le(a: Vec3A, b: Vec3A) -> Self {
+        a.cmple(b)
+    }
+
+    /// Creates a vector mask containing the result of a `<` comparison for each element of
+    /// `a` and `b`.
+    ///
+    /// This is equivalent to [`Vec3A::cmplt`].
+    #[inline]
+    #[must_use]
+    pub fn from_cmp_lt(a: Vec3A, b: Vec3A) -> Self {
+        a.cmplt(b)
+    }
+
+    /// Creates a vector mask by calling `pred` with each element of `v`.
+    #[inline]
+    #[must_use]
+    pub fn mask_where<F>(v: Vec3A, pred: F) -> Self
+    where
+        F: Fn(f32) -> bool,
+    {
+        let a = v.to_array();
+        Self::from_fn(|i| pred(a[i]))
+    }
+
     /// Returns a bitmask with the lowest 3 bits set from the elements of `self`.
     ///
     /// A true element results in a `1` bit and a false element in a `0` bit.  Element `x` goes
@@ -84,6 +167,47 @@ impl BVec3A {
         self.bitmask() == 0x7
     }
 
+    /// Returns true if the first `n` elements are all true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn all_n(self, n: usize) -> bool {
+        assert!(n <= 3, "n out of bounds");
+        let mask = (1 << n) - 1;
+        self.bitmask() & mask == mask
+    }
+
+    /// Returns true if any of the first `n` elements are true, false otherwise.
+    ///
+    /// Panics if `n` is greater than 3.
+    #[inline]
+    #[must_use]
+    pub fn any_n(self, n: usize) -> bool {
+        assert!(n <= 3, "n out of bounds");
+        self.bitmask() & ((1 << n) - 1) != 0
+    }
+
+    /// Returns true if `f` returns true for the index of every element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn all_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).all()
+    }
+
+    /// Returns true if `f` returns true for the index of any element, false otherwise.
+    #[inline]
+    #[must_use]
+    pub fn any_by<F>(f: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_fn(f).any()
+    }
+
     /// Tests the value at `index`.
     ///
     /// Panics if `index` is greater than 2.
@@ -94,6 +218,17 @@ impl BVec3A {
         self.0.test(index)
     }
 
+    /// Returns the value at `index`, or `None` if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn get(self, index: usize) -> Option<bool> {
+        if index < 3 {
+            Some(self.test(index))
+        } else {
+            None
+        }
+    }
+
     /// Sets the element at `index`.
     ///
     /// Panics if `index` is greater than 2.
@@ -103,6 +238,192 @@ impl BVec3A {
         self.0.set(index, value)
     }
 
+    /// Returns a vector mask containing the logical implication `self -> rhs` of each element.
+    ///
+    /// An element is false only when it is true in `self` and false in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn implies(self, rhs: Self) -> Self {
+        !self | rhs
+    }
+
+    /// Returns a vector mask containing the logical equivalence `self <-> rhs` of each element.
+    ///
+    /// An element is true when it is the same in `self` and `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn iff(self, rhs: Self) -> Self {
+        !(self ^ rhs)
+    }
+
+    /// Returns a reference to the elements of `self` as an array of `u32` lanes without copying.
+    ///
+    /// Each element is `0` if false and `0xffff_ffff` if true. This is a view of the underlying
+    /// 16 byte aligned storage, which is always large enough and sufficiently aligned for
+    /// `[u32; 3]`.
+    #[inline]
+    #[must_use]
+    pub fn as_u32_array_ref(&self) -> &[u32; 3] {
+        unsafe { &*(self as *const Self as *const [u32; 3]) }
+    }
+
+    /// Returns the index of the first true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn first_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some(bitmask.trailing_zeros() as usize)
+        }
+    }
+
+    /// Returns the index of the last true element, or `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn last_set(self) -> Option<usize> {
+        let bitmask = self.bitmask();
+        if bitmask == 0 {
+            None
+        } else {
+            Some((31 - bitmask.leading_zeros()) as usize)
+        }
+    }
+
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`. This is equivalent to [`Vec3A::select`].
+    ///
+    /// The padding element of the result is unspecified.
+    #[inline]
+    #[must_use]
+    pub fn select(self, if_true: Vec3A, if_false: Vec3A) -> Vec3A {
+        Vec3A::select(self, if_true, if_false)
+    }
+
+    /// Returns the number of consecutive true elements starting from `x`.
+    #[inline]
+    #[must_use]
+    pub fn leading_trues(self) -> u32 {
+        self.bitmask().trailing_ones()
+    }
+
+    /// Returns the number of consecutive true elements starting from `z` going backwards.
+    #[inline]
+    #[must_use]
+    pub fn trailing_trues(self) -> u32 {
+        (self.bitmask() << 29).leading_ones()
+    }
+
+    /// Toggles the element at `index`.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    pub fn toggle(&mut self, index: usize) {
+        assert!(index < 3, "index out of bounds");
+        *self ^= Self::from_fn(|i| i == index);
+    }
+
+    /// Returns a copy of `self` with the element at `index` toggled.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn toggled(mut self, index: usize) -> Self {
+        self.toggle(index);
+        self
+    }
+
+    /// Returns a vector mask with all elements set to the element of `self` at `index`.
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    #[must_use]
+    pub fn splat_lane(self, index: usize) -> Self {
+        Self::splat(self.test(index))
+    }
+
+    /// Returns the element of `values` at the index of the first true element of `self`, or
+    /// `None` if all elements are false.
+    #[inline]
+    #[must_use]
+    pub fn select_first_true_lane<T>(self, values: [T; 3]) -> Option<T> {
+        let index = self.first_set()?;
+        values.into_iter().nth(index)
+    }
+
+    /// Folds every element of `self` into an accumulator by applying `f` to each element in
+    /// order, starting with `x`.
+    #[inline]
+    #[must_use]
+    pub fn fold<T, F>(self, init: T, f: F) -> T
+    where
+        F: FnMut(T, bool) -> T,
+    {
+        self.into_bool_array().into_iter().fold(init, f)
+    }
+
+    /// Creates an array from the elements in `if_true` and `if_false`, selecting which to use
+    /// for each element of `self`.
+    ///
+    /// A true element in the mask uses the corresponding element from `if_true`, and false
+    /// uses the element from `if_false`.
+    #[inline]
+    #[must_use]
+    pub fn select_array<T: Copy>(self, if_true: [T; 3], if_false: [T; 3]) -> [T; 3] {
+        let mask = self.into_bool_array();
+        core::array::from_fn(|i| if mask[i] { if_true[i] } else { if_false[i] })
+    }
+
+    /// Returns a vector mask with the elements of `self` in reverse order.
+    #[inline]
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let [x, y, z] = self.into_bool_array();
+        Self::new(z, y, x)
+    }
+
+    /// Returns the number of adjacent pairs of elements in `self` which differ.
+    #[inline]
+    #[must_use]
+    pub fn count_transitions(self) -> u32 {
+        let bitmask = self.bitmask();
+        ((bitmask ^ (bitmask >> 1)) & 0b11).count_ones()
+    }
+
+    /// Returns a vector mask with every element of `self` inverted.
+    ///
+    /// This is computed by XOR with [`Self::TRUE`] and is equivalent to `!self`.
+    #[inline]
+    #[must_use]
+    pub fn xor_all(self) -> Self {
+        self ^ Self::TRUE
+    }
+
+    /// Returns the index of the only true element, or `None` if no elements or more than one
+    /// element are true.
+    #[inline]
+    #[must_use]
+    pub fn single(self) -> Option<usize> {
+        if self.bitmask().count_ones() == 1 {
+            self.first_set()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a vector mask containing the result of `self & !rhs` for each element.
+    ///
+    /// This can be used to clear the elements of `self` which are true in `rhs`.
+    #[inline]
+    #[must_use]
+    pub fn and_not(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+
     #[inline]
     #[must_use]
     fn into_bool_array(self) -> [bool; 3] {
@@ -287,7 +608,7 @@ impl Not for BVec3A {
     type Output = Self;
     #[inline]
     fn not(self) -> Self {
-        Self(!self.0)
+        self.xor_all()
     }
 }
 
@@ -299,6 +620,18 @@ impl Not for &BVec3A {
     }
 }
 
+impl Index<usize> for BVec3A {
+    type Output = bool;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        if self.test(index) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
 impl From<BVec3A> for mask32x4 {
     #[inline]
     fn from(t: BVec3A) -> Self {
@@ -341,9 +674,31 @@ impl From<BVec3A> for [bool; 3] {
     }
 }
 
+impl From<[u32; 3]> for BVec3A {
+    /// Creates a vector mask treating any non-zero element of `a` as true.
+    #[inline]
+    fn from(a: [u32; 3]) -> Self {
+        Self::new(a[0] != 0, a[1] != 0, a[2] != 0)
+    }
+}
+
 impl From<BVec3A> for [u32; 3] {
     #[inline]
     fn from(mask: BVec3A) -> Self {
         mask.into_u32_array()
     }
 }
+
+impl PartialEq<[bool; 3]> for BVec3A {
+    #[inline]
+    fn eq(&self, rhs: &[bool; 3]) -> bool {
+        self.into_bool_array().eq(rhs)
+    }
+}
+
+impl PartialEq<BVec3A> for [bool; 3] {
+    #[inline]
+    fn eq(&self, rhs: &BVec3A) -> bool {
+        self.eq(&rhs.into_bool_array())
+    }
+}
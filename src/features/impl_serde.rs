@@ -968,7 +968,7 @@ mod bool {
             state.serialize_field(&a[0])?;
             state.serialize_field(&a[1])?;
             state.serialize_field(&a[2])?;
-            state.serialize_field(&a[2])?;
+            state.serialize_field(&a[3])?;
             state.end()
         }
     }
@@ -1031,6 +1031,18 @@ mod bool {
         let deserialized = serde_json::from_str::<BVec4A>(SX5);
         assert!(deserialized.is_err());
     }
+
+    #[cfg(not(feature = "scalar-math"))]
+    #[test]
+    fn test_bvec4a_serde_lanes() {
+        let a = BVec4A::new(true, false, false, true);
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!("[true,false,false,true]", serialized);
+        let deserialized: BVec4A = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, deserialized);
+        let deserialized: BVec4A = serde_json::from_str("[false,true,true,false]").unwrap();
+        assert_eq!(BVec4A::new(false, true, true, false), deserialized);
+    }
 }
 
 mod f32 {
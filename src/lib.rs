@@ -246,6 +246,7 @@ and benchmarks.
 ## Feature gates
 
 * `std` - the default feature, has no dependencies.
+* `alloc` - enables functionality which requires an allocator, implied by `std`.
 * `nostd-libm` - uses `libm` math functions if `std` is not available
 * `scalar-math` - disables SIMD support and uses native alignment for all types.
 * `debug-glam-assert` - adds assertions in debug builds which check the validity of parameters
@@ -290,6 +291,9 @@ compile_error!(
     "You must specify a math backend. Consider enabling either `std`, `libm`, or `nostd-libm`."
 );
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 
@@ -397,6 +397,120 @@ macro_rules! impl_bvec2_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_mask_from_fn, {
+            assert_eq!($mask::from_fn(|i| i % 2 == 0), $mask::new(true, false));
+            assert!($mask::all_by(|i| i < 2));
+            assert!(!$mask::any_by(|i| i > 1));
+        });
+
+        glam_test!(test_mask_implies_iff, {
+            let a = $mask::new(false, true);
+            let b = $mask::new(true, false);
+            assert_eq!(a.implies(b), $mask::new(true, false));
+            assert_eq!(a.iff(b), $mask::FALSE);
+            assert_eq!(a.iff(a), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_first_last_set, {
+            assert_eq!($mask::FALSE.first_set(), None);
+            assert_eq!($mask::FALSE.last_set(), None);
+            assert_eq!($mask::TRUE.first_set(), Some(0));
+            assert_eq!($mask::TRUE.last_set(), Some(1));
+        });
+
+        glam_test!(test_mask_eq_bool_array, {
+            let a = $mask::new(true, false);
+            assert_eq!(a, [true, false]);
+            assert_eq!([true, false], a);
+            assert_ne!(a, [false, false]);
+            assert_ne!([true, true], a);
+        });
+
+        glam_test!(test_mask_leading_trailing_trues, {
+            assert_eq!($mask::new(true, false).leading_trues(), 1);
+            assert_eq!($mask::new(true, false).trailing_trues(), 0);
+            assert_eq!($mask::TRUE.leading_trues(), 2);
+            assert_eq!($mask::TRUE.trailing_trues(), 2);
+        });
+
+        glam_test!(test_mask_toggle, {
+            let a = $mask::new(true, false);
+            for i in 0..2 {
+                assert_eq!(a.toggled(i).bitmask(), a.bitmask() ^ (1 << i));
+            }
+            should_panic!({ a.toggled(2) });
+        });
+
+        glam_test!(test_mask_splat_lane, {
+            let a = $mask::new(false, true);
+            assert_eq!(a.splat_lane(0), $mask::FALSE);
+            assert_eq!(a.splat_lane(1), $mask::TRUE);
+            should_panic!({ a.splat_lane(2) });
+        });
+
+        glam_test!(test_mask_from_array_u32, {
+            assert_eq!($mask::from([1, 0]), $mask::new(true, false));
+            assert_eq!(
+                Into::<[u32; 2]>::into($mask::from([0, 2])),
+                [0, 0xff_ff_ff_ff]
+            );
+        });
+
+        glam_test!(test_mask_index_get, {
+            let a = $mask::new(false, true);
+            assert_eq!(a[0], false);
+            assert_eq!(a[1], true);
+            assert_eq!(a.get(1), Some(true));
+            assert_eq!(a.get(2), None);
+            should_panic!({ a[2] });
+        });
+
+        glam_test!(test_mask_fold, {
+            let a = $mask::new(true, true);
+            assert_eq!(a.fold(0, |count, b| count + b as u32), 2);
+        });
+
+        glam_test!(test_mask_select_array, {
+            let a = $mask::new(false, true);
+            assert_eq!(a.select_array([1_u8, 2], [3, 4]), [3, 2]);
+        });
+
+        glam_test!(test_mask_reverse, {
+            assert_eq!($mask::new(true, false).reverse(), $mask::new(false, true));
+        });
+
+        glam_test!(test_mask_count_transitions, {
+            assert_eq!($mask::new(true, false).count_transitions(), 1);
+            assert_eq!($mask::TRUE.count_transitions(), 0);
+        });
+
+        glam_test!(test_mask_xor_all, {
+            let a = $mask::new(true, false);
+            assert_eq!(a.xor_all(), !a);
+            assert_eq!(a.xor_all().bitmask(), (!a).bitmask());
+            assert_eq!(a.xor_all().xor_all(), a);
+            assert_eq!(!!a, a);
+            assert_eq!($mask::TRUE.xor_all(), $mask::FALSE);
+            assert_eq!($mask::FALSE.xor_all(), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_single, {
+            assert_eq!($mask::FALSE.single(), None);
+            assert_eq!($mask::new(true, false).single(), Some(0));
+            assert_eq!($mask::new(false, true).single(), Some(1));
+            assert_eq!($mask::TRUE.single(), None);
+        });
+
+        glam_test!(test_mask_and_not, {
+            let a = $mask::new(true, true);
+            let b = $mask::new(false, true);
+            assert_eq!(a.and_not(b), a & !b);
+            assert_eq!(a.and_not(b).bitmask(), (a & !b).bitmask());
+            assert_eq!(b.and_not(a), b & !a);
+            assert_eq!(a.and_not(a), $mask::FALSE);
+            assert_eq!(a.and_not($mask::FALSE), a);
+        });
     };
 }
 
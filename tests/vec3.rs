@@ -461,6 +461,145 @@ macro_rules! impl_bvec3_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_mask_from_fn, {
+            assert_eq!(
+                $mask::from_fn(|i| i % 2 == 0),
+                $mask::new(true, false, true)
+            );
+            assert!($mask::all_by(|i| i < 3));
+            assert!(!$mask::any_by(|i| i > 2));
+        });
+
+        glam_test!(test_mask_implies_iff, {
+            let a = $mask::new(false, true, true);
+            let b = $mask::new(true, false, true);
+            assert_eq!(a.implies(b), $mask::new(true, false, true));
+            assert_eq!(a.iff(b), $mask::new(false, false, true));
+            assert_eq!(a.iff(a), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_first_last_set, {
+            assert_eq!($mask::FALSE.first_set(), None);
+            assert_eq!($mask::FALSE.last_set(), None);
+            assert_eq!($mask::TRUE.first_set(), Some(0));
+            assert_eq!($mask::TRUE.last_set(), Some(2));
+            assert_eq!($mask::new(false, true, false).first_set(), Some(1));
+            assert_eq!($mask::new(false, true, false).last_set(), Some(1));
+        });
+
+        glam_test!(test_mask_eq_bool_array, {
+            let a = $mask::new(true, false, true);
+            assert_eq!(a, [true, false, true]);
+            assert_eq!([true, false, true], a);
+            assert_ne!(a, [false, false, true]);
+            assert_ne!([true, true, true], a);
+        });
+
+        glam_test!(test_mask_leading_trailing_trues, {
+            assert_eq!($mask::new(true, true, false).leading_trues(), 2);
+            assert_eq!($mask::new(true, true, false).trailing_trues(), 0);
+            assert_eq!($mask::new(false, true, true).trailing_trues(), 2);
+            assert_eq!($mask::TRUE.leading_trues(), 3);
+            assert_eq!($mask::TRUE.trailing_trues(), 3);
+        });
+
+        glam_test!(test_mask_toggle, {
+            let a = $mask::new(true, false, true);
+            for i in 0..3 {
+                assert_eq!(a.toggled(i).bitmask(), a.bitmask() ^ (1 << i));
+            }
+            should_panic!({ a.toggled(3) });
+        });
+
+        glam_test!(test_mask_splat_lane, {
+            let a = $mask::new(false, true, false);
+            assert_eq!(a.splat_lane(0), $mask::FALSE);
+            assert_eq!(a.splat_lane(1), $mask::TRUE);
+            assert_eq!(a.splat_lane(2), $mask::FALSE);
+            should_panic!({ a.splat_lane(3) });
+        });
+
+        glam_test!(test_mask_from_array_u32, {
+            assert_eq!(
+                $mask::from([1, 0, 0xff_ff_ff_ff]),
+                $mask::new(true, false, true)
+            );
+            assert_eq!(
+                Into::<[u32; 3]>::into($mask::from([0, 2, 0])),
+                [0, 0xff_ff_ff_ff, 0]
+            );
+        });
+
+        glam_test!(test_mask_all_any_n, {
+            let a = $mask::new(true, true, false);
+            assert!(a.all_n(2));
+            assert!(!a.all_n(3));
+            assert!(!$mask::new(false, false, true).any_n(2));
+            assert!($mask::new(false, false, true).any_n(3));
+            should_panic!({ a.all_n(4) });
+        });
+
+        glam_test!(test_mask_index_get, {
+            let a = $mask::new(true, false, true);
+            assert_eq!(a[0], true);
+            assert_eq!(a[1], false);
+            assert_eq!(a[2], true);
+            assert_eq!(a.get(2), Some(true));
+            assert_eq!(a.get(3), None);
+            should_panic!({ a[3] });
+        });
+
+        glam_test!(test_mask_fold, {
+            let a = $mask::new(true, false, true);
+            assert_eq!(a.fold(0, |count, b| count + b as u32), 2);
+        });
+
+        glam_test!(test_mask_select_array, {
+            let a = $mask::new(true, false, true);
+            assert_eq!(a.select_array([1_u8, 2, 3], [4, 5, 6]), [1, 5, 3]);
+        });
+
+        glam_test!(test_mask_reverse, {
+            assert_eq!(
+                $mask::new(true, true, false).reverse(),
+                $mask::new(false, true, true)
+            );
+            assert_eq!($mask::TRUE.reverse(), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_count_transitions, {
+            assert_eq!($mask::new(true, false, true).count_transitions(), 2);
+            assert_eq!($mask::new(false, false, true).count_transitions(), 1);
+            assert_eq!($mask::TRUE.count_transitions(), 0);
+        });
+
+        glam_test!(test_mask_xor_all, {
+            let a = $mask::new(true, false, true);
+            assert_eq!(a.xor_all(), !a);
+            assert_eq!(a.xor_all().bitmask(), (!a).bitmask());
+            assert_eq!(a.xor_all().xor_all(), a);
+            assert_eq!(!!a, a);
+            assert_eq!($mask::TRUE.xor_all(), $mask::FALSE);
+            assert_eq!($mask::FALSE.xor_all(), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_single, {
+            assert_eq!($mask::FALSE.single(), None);
+            assert_eq!($mask::new(false, true, false).single(), Some(1));
+            assert_eq!($mask::new(true, false, true).single(), None);
+            assert_eq!($mask::TRUE.single(), None);
+        });
+
+        glam_test!(test_mask_and_not, {
+            let a = $mask::new(true, true, false);
+            let b = $mask::new(false, true, true);
+            assert_eq!(a.and_not(b), a & !b);
+            assert_eq!(a.and_not(b).bitmask(), (a & !b).bitmask());
+            assert_eq!(b.and_not(a), b & !a);
+            assert_eq!(a.and_not(a), $mask::FALSE);
+            assert_eq!(a.and_not($mask::FALSE), a);
+        });
     };
 }
 
@@ -2412,6 +2551,54 @@ mod bvec3a {
     });
 
     impl_bvec3_tests!(BVec3A, bvec3a);
+
+    glam_test!(test_mask_from_cmp, {
+        use glam::Vec3A;
+        let a = Vec3A::new(1.0, 2.0, 3.0);
+        let b = Vec3A::new(3.0, 2.0, 1.0);
+        assert_eq!(BVec3A::from_cmp_eq(a, b), a.cmpeq(b));
+        assert_eq!(BVec3A::from_cmp_ne(a, b), a.cmpne(b));
+        assert_eq!(BVec3A::from_cmp_ge(a, b), a.cmpge(b));
+        assert_eq!(BVec3A::from_cmp_gt(a, b), a.cmpgt(b));
+        assert_eq!(BVec3A::from_cmp_le(a, b), a.cmple(b));
+        assert_eq!(BVec3A::from_cmp_lt(a, b), a.cmplt(b));
+        assert_eq!(BVec3A::from_cmp_lt(a, b), bvec3a(true, false, false));
+    });
+
+    glam_test!(test_mask_as_u32_array_ref, {
+        for mask in [bvec3a(true, false, true), BVec3A::TRUE, BVec3A::FALSE] {
+            assert_eq!(mask.as_u32_array_ref(), &Into::<[u32; 3]>::into(mask));
+        }
+
+        let mut mask = BVec3A::FALSE;
+        mask.set(1, true);
+        assert_eq!(mask.as_u32_array_ref()[1], 0xff_ff_ff_ff);
+    });
+
+    glam_test!(test_mask_select, {
+        use glam::Vec3A;
+        let a = Vec3A::new(1.0, 2.0, 3.0);
+        let b = Vec3A::new(4.0, 5.0, 6.0);
+        for bits in 0..8 {
+            let mask = BVec3A::new(bits & 1 != 0, bits & 2 != 0, bits & 4 != 0);
+            let expected = Vec3A::new(
+                if bits & 1 != 0 { a.x } else { b.x },
+                if bits & 2 != 0 { a.y } else { b.y },
+                if bits & 4 != 0 { a.z } else { b.z },
+            );
+            assert_eq!(mask.select(a, b), expected);
+            assert_eq!(mask.select(a, b), Vec3A::select(mask, a, b));
+        }
+    });
+
+    glam_test!(test_mask_where, {
+        use glam::Vec3A;
+        let v = Vec3A::new(1.0, 5.0, -2.0);
+        assert_eq!(
+            BVec3A::mask_where(v, |x| x > 2.0),
+            BVec3A::new(false, true, false)
+        );
+    });
 }
 
 mod vec3 {
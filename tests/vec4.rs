@@ -526,6 +526,227 @@ macro_rules! impl_bvec4_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_mask_from_fn, {
+            assert_eq!(
+                $mask::from_fn(|i| i % 2 == 0),
+                $mask::new(true, false, true, false)
+            );
+            assert_eq!($mask::from_fn(|_| true), $mask::TRUE);
+            assert_eq!($mask::from_fn(|_| false), $mask::FALSE);
+
+            assert!($mask::all_by(|i| i < 4));
+            assert!(!$mask::all_by(|i| i % 2 == 0));
+            assert!($mask::any_by(|i| i % 2 == 0));
+            assert!(!$mask::any_by(|i| i > 3));
+        });
+
+        glam_test!(test_mask_consts, {
+            assert!($mask::TRUE.all());
+            assert!(!$mask::FALSE.any());
+            assert_eq!($mask::TRUE, $mask::splat(true));
+            assert_eq!($mask::FALSE, $mask::splat(false));
+        });
+
+        glam_test!(test_mask_implies_iff, {
+            let a = $mask::new(false, false, true, true);
+            let b = $mask::new(false, true, false, true);
+            assert_eq!(a.implies(b), $mask::new(true, true, false, true));
+            assert_eq!(b.implies(a), $mask::new(true, false, true, true));
+            assert_eq!(a.iff(b), $mask::new(true, false, false, true));
+            assert_eq!(b.iff(a), $mask::new(true, false, false, true));
+            assert_eq!(a.implies(a), $mask::TRUE);
+            assert_eq!(a.iff(a), $mask::TRUE);
+            assert_eq!(a.iff(!a), $mask::FALSE);
+        });
+
+        glam_test!(test_mask_first_last_set, {
+            assert_eq!($mask::FALSE.first_set(), None);
+            assert_eq!($mask::FALSE.last_set(), None);
+            assert_eq!($mask::TRUE.first_set(), Some(0));
+            assert_eq!($mask::TRUE.last_set(), Some(3));
+            assert_eq!($mask::new(false, true, false, false).first_set(), Some(1));
+            assert_eq!($mask::new(false, true, false, false).last_set(), Some(1));
+            assert_eq!($mask::new(false, true, true, false).first_set(), Some(1));
+            assert_eq!($mask::new(false, true, true, false).last_set(), Some(2));
+            assert_eq!($mask::new(false, false, false, true).first_set(), Some(3));
+            assert_eq!($mask::new(true, false, false, false).last_set(), Some(0));
+        });
+
+        glam_test!(test_mask_eq_bool_array, {
+            let a = $mask::new(true, false, true, false);
+            assert_eq!(a, [true, false, true, false]);
+            assert_eq!([true, false, true, false], a);
+            assert_ne!(a, [false, false, true, false]);
+            assert_ne!([true, false, true, true], a);
+        });
+
+        glam_test!(test_mask_leading_trailing_trues, {
+            assert_eq!($mask::new(true, true, false, true).leading_trues(), 2);
+            assert_eq!($mask::new(true, true, false, true).trailing_trues(), 1);
+            assert_eq!($mask::new(false, true, true, true).leading_trues(), 0);
+            assert_eq!($mask::new(false, true, true, true).trailing_trues(), 3);
+            assert_eq!($mask::TRUE.leading_trues(), 4);
+            assert_eq!($mask::TRUE.trailing_trues(), 4);
+            assert_eq!($mask::FALSE.leading_trues(), 0);
+            assert_eq!($mask::FALSE.trailing_trues(), 0);
+        });
+
+        glam_test!(test_mask_toggle, {
+            let a = $mask::new(true, false, true, false);
+            for i in 0..4 {
+                let mut b = a;
+                b.toggle(i);
+                assert_eq!(b.bitmask(), a.bitmask() ^ (1 << i));
+                assert_eq!(a.toggled(i), b);
+                assert_eq!(b.toggled(i), a);
+            }
+
+            should_panic!({
+                let mut a = $mask::FALSE;
+                a.toggle(4)
+            });
+        });
+
+        glam_test!(test_mask_splat_lane, {
+            let a = $mask::new(false, false, true, false);
+            assert_eq!(a.splat_lane(0), $mask::FALSE);
+            assert_eq!(a.splat_lane(1), $mask::FALSE);
+            assert_eq!(a.splat_lane(2), $mask::TRUE);
+            assert_eq!(a.splat_lane(3), $mask::FALSE);
+            assert_eq!((!a).splat_lane(3), $mask::TRUE);
+            should_panic!({ a.splat_lane(4) });
+        });
+
+        glam_test!(test_mask_from_array_u32, {
+            assert_eq!(
+                $mask::from([1, 0, 0xff_ff_ff_ff, 0]),
+                $mask::new(true, false, true, false)
+            );
+            assert_eq!(
+                Into::<[u32; 4]>::into($mask::from([0, 2, 0x8000_0000, 0])),
+                [0, 0xff_ff_ff_ff, 0xff_ff_ff_ff, 0]
+            );
+            assert_eq!($mask::from([0; 4]), $mask::FALSE);
+        });
+
+        glam_test!(test_mask_select_first_true_lane, {
+            let values = ["x", "y", "z", "w"];
+            assert_eq!(
+                $mask::new(false, true, false, true).select_first_true_lane(values),
+                Some("y")
+            );
+            assert_eq!($mask::TRUE.select_first_true_lane(values), Some("x"));
+            assert_eq!(
+                $mask::new(false, false, false, true).select_first_true_lane(values),
+                Some("w")
+            );
+            assert_eq!($mask::FALSE.select_first_true_lane(values), None);
+        });
+
+        glam_test!(test_mask_all_any_n, {
+            let a = $mask::new(true, true, false, true);
+            assert!(a.all_n(0));
+            assert!(a.all_n(2));
+            assert!(!a.all_n(3));
+            assert!(!a.all_n(4));
+            assert!(!a.any_n(0));
+            assert!(a.any_n(1));
+
+            let b = $mask::new(false, false, false, true);
+            assert!(!b.any_n(3));
+            assert!(b.any_n(4));
+            assert!(!$mask::new(true, true, true, false).all_n(4));
+            assert!($mask::new(true, true, true, false).all_n(3));
+
+            should_panic!({ a.all_n(5) });
+            should_panic!({ a.any_n(5) });
+        });
+
+        glam_test!(test_mask_index_get, {
+            let a = $mask::new(true, false, false, true);
+            assert_eq!(a[0], true);
+            assert_eq!(a[1], false);
+            assert_eq!(a[2], false);
+            assert_eq!(a[3], true);
+            assert_eq!(a.get(0), Some(true));
+            assert_eq!(a.get(1), Some(false));
+            assert_eq!(a.get(2), Some(false));
+            assert_eq!(a.get(3), Some(true));
+            assert_eq!(a.get(4), None);
+
+            should_panic!({ a[4] });
+        });
+
+        glam_test!(test_mask_fold, {
+            for bits in 0..16 {
+                let a = $mask::from_fn(|i| bits & (1 << i) != 0);
+                assert_eq!(
+                    a.fold(0, |count, b| count + b as u32),
+                    a.bitmask().count_ones()
+                );
+            }
+            assert_eq!(
+                $mask::new(true, false, true, true).fold(0, |acc, b| (acc << 1) | b as u32),
+                0b1011
+            );
+        });
+
+        glam_test!(test_mask_select_array, {
+            let a = $mask::new(true, false, false, true);
+            assert_eq!(a.select_array([1_u8, 2, 3, 4], [5, 6, 7, 8]), [1, 6, 7, 4]);
+            assert_eq!($mask::TRUE.select_array(["a"; 4], ["b"; 4]), ["a"; 4]);
+            assert_eq!($mask::FALSE.select_array(["a"; 4], ["b"; 4]), ["b"; 4]);
+        });
+
+        glam_test!(test_mask_reverse, {
+            assert_eq!(
+                $mask::new(true, false, false, false).reverse(),
+                $mask::new(false, false, false, true)
+            );
+            for bits in 0..16_u32 {
+                let a = $mask::from_fn(|i| bits & (1 << i) != 0);
+                assert_eq!(a.reverse().bitmask(), bits.reverse_bits() >> 28);
+                assert_eq!(a.reverse().reverse(), a);
+            }
+        });
+
+        glam_test!(test_mask_count_transitions, {
+            assert_eq!($mask::new(true, false, true, false).count_transitions(), 3);
+            assert_eq!($mask::new(true, true, false, false).count_transitions(), 1);
+            assert_eq!($mask::TRUE.count_transitions(), 0);
+            assert_eq!($mask::FALSE.count_transitions(), 0);
+        });
+
+        glam_test!(test_mask_xor_all, {
+            let a = $mask::new(true, false, true, false);
+            assert_eq!(a.xor_all(), !a);
+            assert_eq!(a.xor_all().bitmask(), (!a).bitmask());
+            assert_eq!(a.xor_all().xor_all(), a);
+            assert_eq!(!!a, a);
+            assert_eq!($mask::TRUE.xor_all(), $mask::FALSE);
+            assert_eq!($mask::FALSE.xor_all(), $mask::TRUE);
+        });
+
+        glam_test!(test_mask_single, {
+            assert_eq!($mask::FALSE.single(), None);
+            assert_eq!($mask::new(false, false, true, false).single(), Some(2));
+            assert_eq!($mask::new(false, true, false, true).single(), None);
+            assert_eq!($mask::TRUE.single(), None);
+            for i in 0..4 {
+                assert_eq!($mask::from_fn(|j| j == i).single(), Some(i));
+            }
+        });
+
+        glam_test!(test_mask_and_not, {
+            let a = $mask::new(true, true, false, false);
+            let b = $mask::new(false, true, false, true);
+            assert_eq!(a.and_not(b), a & !b);
+            assert_eq!(a.and_not(b).bitmask(), (a & !b).bitmask());
+            assert_eq!(b.and_not(a), b & !a);
+            assert_eq!(a.and_not(a), $mask::FALSE);
+            assert_eq!(a.and_not($mask::FALSE), a);
+        });
     };
 }
 
@@ -2275,6 +2496,138 @@ mod bvec4a {
     });
 
     impl_bvec4_tests!(BVec4A, bvec4a);
+
+    glam_test!(test_mask_from_cmp, {
+        use glam::Vec4;
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(4.0, 2.0, 1.0, 4.0);
+        assert_eq!(BVec4A::from_cmp_eq(a, b).bitmask(), a.cmpeq(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_ne(a, b).bitmask(), a.cmpne(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_ge(a, b).bitmask(), a.cmpge(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_gt(a, b).bitmask(), a.cmpgt(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_le(a, b).bitmask(), a.cmple(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_lt(a, b).bitmask(), a.cmplt(b).bitmask());
+        assert_eq!(BVec4A::from_cmp_lt(a, b), bvec4a(true, false, false, false));
+    });
+
+    glam_test!(test_mask_as_u32_array_ref, {
+        for mask in [
+            bvec4a(true, false, false, true),
+            BVec4A::TRUE,
+            BVec4A::FALSE,
+        ] {
+            assert_eq!(mask.as_u32_array_ref(), &Into::<[u32; 4]>::into(mask));
+        }
+
+        let mut mask = BVec4A::FALSE;
+        mask.set(1, true);
+        assert_eq!(mask.as_u32_array_ref()[1], 0xff_ff_ff_ff);
+    });
+
+    glam_test!(test_mask_to_from_bvec, {
+        use glam::{bvec2, bvec3};
+        let a = bvec4a(true, false, true, true);
+        assert_eq!(a.to_bvec3(), bvec3(true, false, true));
+        assert_eq!(a.to_bvec2(), bvec2(true, false));
+        assert_eq!(
+            BVec4A::from_bvec3(a.to_bvec3()),
+            bvec4a(true, false, true, false)
+        );
+        assert_eq!(
+            BVec4A::from_bvec2(a.to_bvec2()),
+            bvec4a(true, false, false, false)
+        );
+        assert_eq!(
+            BVec4A::from_bvec3(a.to_bvec3()).bitmask(),
+            a.to_bvec3().bitmask()
+        );
+        assert_eq!(
+            BVec4A::from_bvec2(a.to_bvec2()).bitmask(),
+            a.to_bvec2().bitmask()
+        );
+    });
+
+    glam_test!(test_mask_select, {
+        use glam::Vec4;
+        let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        let b = Vec4::new(5.0, 6.0, 7.0, 8.0);
+        assert_eq!(BVec4A::TRUE.select(a, b), a);
+        assert_eq!(BVec4A::FALSE.select(a, b), b);
+        assert_eq!(
+            bvec4a(true, false, false, true).select(a, b),
+            Vec4::new(1.0, 6.0, 7.0, 4.0)
+        );
+    });
+
+    #[cfg(feature = "alloc")]
+    glam_test!(test_pack_unpack_masks, {
+        use glam::bool::{pack_masks, unpack_masks};
+
+        let mut seed = 0x2545_f491_u32;
+        let masks: Vec<BVec4A> = (0..37)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                BVec4A::from_fn(|i| seed & (1 << i) != 0)
+            })
+            .collect();
+
+        let bytes = pack_masks(&masks);
+        assert_eq!(bytes.len(), 19);
+        assert_eq!(unpack_masks(&bytes, masks.len()), masks);
+        assert_eq!(unpack_masks(&bytes, 4), masks[..4]);
+
+        assert_eq!(
+            pack_masks(&[bvec4a(true, false, false, false), BVec4A::TRUE]),
+            [0xf1]
+        );
+        assert!(pack_masks(&[]).is_empty());
+
+        should_panic!({ unpack_masks(&bytes, 39) });
+    });
+
+    glam_test!(test_mask_to_sign_vec4, {
+        use glam::Vec4;
+        assert_eq!(
+            bvec4a(true, false, false, true).to_sign_vec4(),
+            Vec4::new(-1.0, 1.0, 1.0, -1.0)
+        );
+        assert_eq!(BVec4A::TRUE.to_sign_vec4(), Vec4::NEG_ONE);
+        assert_eq!(BVec4A::FALSE.to_sign_vec4(), Vec4::ONE);
+    });
+
+    glam_test!(test_mask_hash_matches_bvec4, {
+        use glam::BVec4;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(t: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            t.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for bits in 0..16 {
+            let a = BVec4A::from_fn(|i| bits & (1 << i) != 0);
+            let b = BVec4::from_fn(|i| bits & (1 << i) != 0);
+            assert_eq!(a.bitmask(), b.bitmask());
+            assert_eq!(hash_of(a), hash_of(b));
+        }
+    });
+
+    glam_test!(test_mask_where, {
+        use glam::Vec4;
+        let v = Vec4::new(1.0, 5.0, -2.0, 10.0);
+        assert_eq!(
+            BVec4A::mask_where(v, |x| x > 2.0),
+            BVec4A::new(false, true, false, true)
+        );
+        assert_eq!(
+            BVec4A::mask_where(v, |x| x > 2.0).bitmask(),
+            v.cmpgt(Vec4::splat(2.0)).bitmask()
+        );
+    });
 }
 
 mod vec4 {